@@ -1,7 +1,7 @@
 
-use std::collections::hash_map::DefaultHasher;
-use std::hash::{Hash, Hasher};
+use sha2::{Digest, Sha256};
 use hex;
+
 fn main(){
     println!("Hello, world!");
 
@@ -15,53 +15,60 @@ fn main(){
 
     ////===============
 
-    let evmAddress = convertToEvmAddress(&result);
-    println!("the evm convert result is {}", evmAddress);
+    match convertToEvmAddress(&result) {
+        Ok(evmAddress) => println!("the evm convert result is {}", evmAddress),
+        Err(e) => println!("the ob address failed to verify: {}", e),
+    }
 }
 
 
+// Bitcoin base58check-style double SHA-256: hashes the canonical
+// (lowercased) payload twice and keeps the first 4 bytes as a checksum, so
+// the result is both a real digest and resistant to case-mangling.
+fn sha256(input: &str) -> [u8; 4] {
+    let first = Sha256::digest(input.as_bytes());
+    let second = Sha256::digest(first);
 
-fn sha256(input: &str) -> String {
-    let mut hasher = DefaultHasher::new();
-    input.hash(&mut hasher);
-    let result = hasher.finish();
-
-    format!("{:x}", result)
+    let mut checksum = [0u8; 4];
+    checksum.copy_from_slice(&second[..4]);
+    checksum
 }
 
 
 //OB99ec891ff6602457efc2c5086c8926f4fe78cebc02a79a55485a6c56aca2b5723735
 fn convertToOBAddress(prefix: &str, evm_address: &str) -> String {
-    let mut address = evm_address.to_string();
-
-    let result = sha256(address.as_str());
-    let mut hex = hex::encode(result);
-    hex.truncate(4);
+    let canonical = evm_address[2..].to_lowercase();
+    let checksum = hex::encode(sha256(&canonical));
 
     let mut address = prefix.to_string();
-    address.push_str(&evm_address[2..]);
-    address.push_str(hex.as_str());
+    address.push_str(&canonical);
+    address.push_str(checksum.as_str());
 
 
     return address;
 }
 
 // 0x99ec891ff6602457efc2c5086c8926f4fe78cebc02a79a55485a6c56aca2b572
-fn convertToEvmAddress(ob_address: &str) -> String {
-
-    let mut address = ob_address[2..].to_string();
-    let evmPrefix = String::from("0x");
-    address.insert_str(0, evmPrefix.as_str());
-    address.truncate(address.len()-4);
+fn convertToEvmAddress(ob_address: &str) -> Result<String, String> {
+    const CHECKSUM_HEX_LEN: usize = 8;
 
-    let result = sha256(address.as_str());
-    let mut hex = hex::encode(result);
-    hex.truncate(4);
+    if ob_address.len() < 2 + CHECKSUM_HEX_LEN {
+        return Err(format!("ob address too short: {}", ob_address));
+    }
 
+    let (payload, verify_code) = ob_address.split_at(ob_address.len() - CHECKSUM_HEX_LEN);
+    let canonical = payload[2..].to_lowercase();
+    let expected_checksum = hex::encode(sha256(&canonical));
 
-    let verifyCode = ob_address[ob_address.len()-4..].to_string();
-    assert_eq!(verifyCode, hex);
+    if verify_code != expected_checksum {
+        return Err(format!(
+            "checksum mismatch: expected {}, got {}",
+            expected_checksum, verify_code
+        ));
+    }
 
+    let mut evm_address = String::from("0x");
+    evm_address.push_str(&canonical);
 
-    return address.to_string();
+    Ok(evm_address)
 }
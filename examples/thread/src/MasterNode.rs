@@ -0,0 +1,29 @@
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use async_std::task;
+use futures::channel::mpsc::UnboundedSender;
+
+use crate::codec::{Codec, Message, WireFormat};
+use crate::SHUTDOWN;
+
+pub async fn start_node(inbound_tx: UnboundedSender<Message>, format: WireFormat) {
+    while !SHUTDOWN.load(Ordering::SeqCst) {
+        let wire = format.encode(&Message::Heartbeat);
+        let msg = match format.decode(&wire) {
+            Ok(msg) => msg,
+            Err(e) => {
+                println!("master node: malformed wire message: {e}");
+                break;
+            }
+        };
+
+        if inbound_tx.unbounded_send(msg).is_err() {
+            break;
+        }
+
+        task::sleep(Duration::from_secs(1)).await;
+    }
+
+    println!("master node shutting down");
+}
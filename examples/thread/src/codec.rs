@@ -0,0 +1,68 @@
+use std::io;
+
+use serde::{Deserialize, Serialize};
+
+/// Message exchanged between the master node and its slave workers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Message {
+    /// Sent by a slave to announce itself to the master, carrying the
+    /// slave's OB address (see `address-convert-tool`).
+    Register { ob_address: String },
+    /// Periodic liveness signal.
+    Heartbeat,
+    /// Ask the master to look up a key in its `KvStore`.
+    Query { key: String },
+    /// Answer to a `Query`, echoing the key alongside the value (`None` if
+    /// it wasn't found).
+    Reply { key: String, value: Option<Vec<u8>> },
+}
+
+/// Encodes/decodes [`Message`]s to and from a specific wire format, chosen
+/// independently of the `Message` type itself so node operators can trade
+/// message size for human-readability without touching node logic.
+pub trait Codec {
+    fn encode(&self, msg: &Message) -> Vec<u8>;
+    fn decode(&self, bytes: &[u8]) -> io::Result<Message>;
+}
+
+fn invalid_data(e: impl std::error::Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e.to_string())
+}
+
+/// Wire format used to (de)serialize a `Message`.
+#[derive(Debug, Clone, Copy)]
+pub enum WireFormat {
+    Bincode,
+    Cbor,
+    Json,
+}
+
+impl WireFormat {
+    /// Picks the format named on the command line (`master cbor`), falling
+    /// back to [`WireFormat::Bincode`] when no format is given.
+    pub fn from_arg(name: Option<&str>) -> WireFormat {
+        match name {
+            Some("cbor") => WireFormat::Cbor,
+            Some("json") => WireFormat::Json,
+            _ => WireFormat::Bincode,
+        }
+    }
+}
+
+impl Codec for WireFormat {
+    fn encode(&self, msg: &Message) -> Vec<u8> {
+        match self {
+            WireFormat::Bincode => bincode::serialize(msg).expect("bincode encode"),
+            WireFormat::Cbor => serde_cbor::to_vec(msg).expect("cbor encode"),
+            WireFormat::Json => serde_json::to_vec(msg).expect("json encode"),
+        }
+    }
+
+    fn decode(&self, bytes: &[u8]) -> io::Result<Message> {
+        match self {
+            WireFormat::Bincode => bincode::deserialize(bytes).map_err(invalid_data),
+            WireFormat::Cbor => serde_cbor::from_slice(bytes).map_err(invalid_data),
+            WireFormat::Json => serde_json::from_slice(bytes).map_err(invalid_data),
+        }
+    }
+}
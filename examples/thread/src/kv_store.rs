@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use crc::{Crc, CRC_32_ISO_HDLC};
+
+const CRC32: Crc<u32> = Crc::<u32>::new(&CRC_32_ISO_HDLC);
+
+/// Sentinel `value_len` marking a tombstone record (a `delete`), so a
+/// deleted key's last record can be told apart from an insert of an empty
+/// value without adding a separate header field.
+const TOMBSTONE_LEN: u32 = u32::MAX;
+
+/// Append-only, CRC-checksummed log used to persist node state as
+/// key/value pairs. Each record is written once and never mutated in
+/// place; the latest record for a key (insert or tombstone) wins on
+/// replay. `compact()` rewrites the log keeping only live records.
+///
+/// On-disk record layout:
+/// `[ crc: u32 ][ key_len: u32 ][ value_len: u32 ][ key bytes ][ value bytes ]`
+/// A `value_len` of `u32::MAX` marks a tombstone; no value bytes follow it.
+pub struct KvStore {
+    file: File,
+    path: PathBuf,
+    index: HashMap<Vec<u8>, u64>,
+}
+
+impl KvStore {
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(&path)?;
+
+        let mut store = KvStore {
+            file,
+            path,
+            index: HashMap::new(),
+        };
+        store.replay()?;
+        Ok(store)
+    }
+
+    /// Scans the log from the start, indexing the offset of the latest
+    /// record for each key. A corrupt or partial record (e.g. left behind
+    /// by a crash mid-write) is treated as the end of the valid log rather
+    /// than a hard error: replay stops there and truncates the file at that
+    /// record's offset, so it stays the last thing in the log and later
+    /// appends follow the last valid record.
+    fn replay(&mut self) -> io::Result<()> {
+        let mut reader = BufReader::new(&self.file);
+        reader.seek(SeekFrom::Start(0))?;
+
+        let mut offset = 0u64;
+        loop {
+            let record_offset = offset;
+            match read_record(&mut reader)? {
+                ReadOutcome::Record { key, record_len, .. } => {
+                    self.index.insert(key, record_offset);
+                    offset += record_len;
+                }
+                ReadOutcome::Eof => break,
+                ReadOutcome::Corrupt { reason } => {
+                    eprintln!(
+                        "kv store: stopping replay at offset {record_offset}, \
+                         discarding partial/corrupt trailing record: {reason}"
+                    );
+                    self.file.set_len(record_offset)?;
+                    break;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn insert(&mut self, key: &[u8], value: &[u8]) -> io::Result<()> {
+        let offset = self.file.seek(SeekFrom::End(0))?;
+
+        let mut writer = BufWriter::new(&self.file);
+        write_record(&mut writer, key, Some(value))?;
+        writer.flush()?;
+
+        self.index.insert(key.to_vec(), offset);
+        Ok(())
+    }
+
+    /// Appends a tombstone for `key`. The key's prior value stays on disk
+    /// until the next `compact()`, but is no longer reachable via `get`.
+    pub fn delete(&mut self, key: &[u8]) -> io::Result<()> {
+        let offset = self.file.seek(SeekFrom::End(0))?;
+
+        let mut writer = BufWriter::new(&self.file);
+        write_record(&mut writer, key, None)?;
+        writer.flush()?;
+
+        self.index.insert(key.to_vec(), offset);
+        Ok(())
+    }
+
+    pub fn get(&mut self, key: &[u8]) -> io::Result<Option<Vec<u8>>> {
+        let offset = match self.index.get(key) {
+            Some(offset) => *offset,
+            None => return Ok(None),
+        };
+
+        let mut reader = BufReader::new(&self.file);
+        reader.seek(SeekFrom::Start(offset))?;
+
+        match read_record(&mut reader)? {
+            ReadOutcome::Record { value, .. } => Ok(value),
+            ReadOutcome::Eof | ReadOutcome::Corrupt { .. } => Ok(None),
+        }
+    }
+
+    /// Rewrites the log into a fresh file keeping only the latest, live
+    /// (non-tombstoned) record for each key, then swaps it in for the
+    /// current log.
+    pub fn compact(&mut self) -> io::Result<()> {
+        let mut live: Vec<(Vec<u8>, u64)> =
+            self.index.iter().map(|(k, v)| (k.clone(), *v)).collect();
+        live.sort_by_key(|(_, offset)| *offset);
+
+        let tmp_path = self.path.with_extension("compact");
+        let tmp_file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&tmp_path)?;
+
+        let mut reader = BufReader::new(&self.file);
+        let mut new_index = HashMap::new();
+        {
+            let mut writer = BufWriter::new(&tmp_file);
+            let mut new_offset = 0u64;
+            for (key, offset) in live {
+                reader.seek(SeekFrom::Start(offset))?;
+                if let ReadOutcome::Record { value: Some(value), .. } = read_record(&mut reader)? {
+                    write_record(&mut writer, &key, Some(&value))?;
+                    new_index.insert(key, new_offset);
+                    new_offset += 4 + 4 + 4 + key.len() as u64 + value.len() as u64;
+                }
+            }
+            writer.flush()?;
+        }
+        tmp_file.sync_all()?;
+
+        std::fs::rename(&tmp_path, &self.path)?;
+        self.file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .open(&self.path)?;
+        self.index = new_index;
+        Ok(())
+    }
+}
+
+fn write_record(writer: &mut impl Write, key: &[u8], value: Option<&[u8]>) -> io::Result<()> {
+    let key_len = key.len() as u32;
+    let value_len = value.map_or(TOMBSTONE_LEN, |v| v.len() as u32);
+
+    let mut digest = CRC32.digest();
+    digest.update(&key_len.to_le_bytes());
+    digest.update(&value_len.to_le_bytes());
+    digest.update(key);
+    if let Some(value) = value {
+        digest.update(value);
+    }
+    let crc = digest.finalize();
+
+    writer.write_all(&crc.to_le_bytes())?;
+    writer.write_all(&key_len.to_le_bytes())?;
+    writer.write_all(&value_len.to_le_bytes())?;
+    writer.write_all(key)?;
+    if let Some(value) = value {
+        writer.write_all(value)?;
+    }
+    Ok(())
+}
+
+/// Outcome of attempting to read one record.
+enum ReadOutcome {
+    /// A full, CRC-verified record. `value` is `None` for a tombstone.
+    Record {
+        key: Vec<u8>,
+        value: Option<Vec<u8>>,
+        record_len: u64,
+    },
+    /// Clean end of log: nothing left to read.
+    Eof,
+    /// A truncated or CRC-mismatched record, only ever valid as the very
+    /// last thing in the log.
+    Corrupt { reason: String },
+}
+
+fn read_record(reader: &mut impl Read) -> io::Result<ReadOutcome> {
+    let mut crc_buf = [0u8; 4];
+    let mut filled = 0;
+    while filled < crc_buf.len() {
+        match reader.read(&mut crc_buf[filled..]) {
+            Ok(0) if filled == 0 => return Ok(ReadOutcome::Eof),
+            Ok(0) => {
+                return Ok(ReadOutcome::Corrupt {
+                    reason: String::from("truncated record header"),
+                })
+            }
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Ok(ReadOutcome::Corrupt { reason: e.to_string() }),
+        }
+    }
+    let expected_crc = u32::from_le_bytes(crc_buf);
+
+    let mut key_len_buf = [0u8; 4];
+    if let Err(e) = reader.read_exact(&mut key_len_buf) {
+        return Ok(ReadOutcome::Corrupt { reason: e.to_string() });
+    }
+    let key_len = u32::from_le_bytes(key_len_buf);
+
+    let mut value_len_buf = [0u8; 4];
+    if let Err(e) = reader.read_exact(&mut value_len_buf) {
+        return Ok(ReadOutcome::Corrupt { reason: e.to_string() });
+    }
+    let value_len = u32::from_le_bytes(value_len_buf);
+    let is_tombstone = value_len == TOMBSTONE_LEN;
+
+    let mut key = vec![0u8; key_len as usize];
+    if let Err(e) = reader.read_exact(&mut key) {
+        return Ok(ReadOutcome::Corrupt { reason: e.to_string() });
+    }
+
+    let value = if is_tombstone {
+        Vec::new()
+    } else {
+        let mut value = vec![0u8; value_len as usize];
+        if let Err(e) = reader.read_exact(&mut value) {
+            return Ok(ReadOutcome::Corrupt { reason: e.to_string() });
+        }
+        value
+    };
+
+    let mut digest = CRC32.digest();
+    digest.update(&key_len_buf);
+    digest.update(&value_len_buf);
+    digest.update(&key);
+    if !is_tombstone {
+        digest.update(&value);
+    }
+    let actual_crc = digest.finalize();
+
+    if actual_crc != expected_crc {
+        return Ok(ReadOutcome::Corrupt {
+            reason: format!("CRC mismatch: expected {expected_crc}, got {actual_crc}"),
+        });
+    }
+
+    let record_len =
+        4 + 4 + 4 + key_len as u64 + if is_tombstone { 0 } else { value_len as u64 };
+
+    Ok(ReadOutcome::Record {
+        key,
+        value: if is_tombstone { None } else { Some(value) },
+        record_len,
+    })
+}
@@ -1,16 +1,44 @@
 
 mod MasterNode;
 mod SlaveNode;
+mod codec;
+mod kv_store;
 
 use std::error::Error;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
 use std::thread;
+use std::time::Duration;
 use async_std::prelude::FutureExt;
+use futures::channel::mpsc::{self, UnboundedSender};
 use futures::{prelude::*, select};
-use async_std::{io, task};
+use async_std::{io, stream, task};
+
+use codec::{Message, WireFormat};
 
 
 const MASTER_SIZE: i32 = 1;
 const SLAVE_SIZE: i32 = 50;
+const TICK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Cooperative cancellation flag checked by spawned master/slave tasks.
+/// Set via the `stop` input command; cleared whenever a new `master` or
+/// `slave` batch is started.
+pub(crate) static SHUTDOWN: AtomicBool = AtomicBool::new(false);
+
+/// Join handles for in-flight master/slave tasks, drained and awaited by
+/// the `stop` command so the pool shuts down deterministically instead of
+/// just flipping `SHUTDOWN` and walking away.
+static TASK_HANDLES: Mutex<Vec<task::JoinHandle<()>>> = Mutex::new(Vec::new());
+
+/// Readiness event driving the main reactor: a line typed on stdin, a
+/// message reported back by a master/slave task, or a periodic tick used
+/// for housekeeping.
+enum Event {
+    Command(String),
+    Inbound(Message),
+    Tick,
+}
 
 //async functions
 #[async_std::main]
@@ -20,17 +48,27 @@ async fn main() -> Result<(), Box<dyn Error>> {
     // Read full lines from stdin
     let mut stdin = io::BufReader::new(io::stdin()).lines().fuse();
 
+    // Messages reported back by spawned master/slave tasks land here so the
+    // reactor can react to them without blocking on the tasks themselves.
+    let (inbound_tx, inbound_rx) = mpsc::unbounded::<Message>();
+    let mut inbound = inbound_rx.fuse();
+
+    let mut ticker = stream::interval(TICK_INTERVAL).fuse();
+
     // Kick it off.
     loop {
-        println!("current msg is 1");
-        select! {
-            line = stdin.select_next_some() => handle_input_line(line.expect("read error")),
-
+        let event = select! {
+            line = stdin.select_next_some() => Event::Command(line.expect("read error")),
+            msg = inbound.select_next_some() => Event::Inbound(msg),
+            _ = ticker.select_next_some() => Event::Tick,
+        };
+
+        match event {
+            Event::Command(line) => handle_input_line(line, inbound_tx.clone()).await,
+            Event::Inbound(msg) => println!("reactor received inbound message: {:?}", msg),
+            Event::Tick => println!("tick"),
         }
     }
-
-
-
 }
 
 
@@ -72,52 +110,48 @@ fn main_sync_spawn(){
 }
 
 
-fn handle_input_line( line: String) {
+async fn handle_input_line(line: String, inbound_tx: UnboundedSender<Message>) {
 
 
     let mut args = line.split(' ');
     match args.next() {
         Some("master") => {
-            let mut localtasks = Vec::new();
+            SHUTDOWN.store(false, Ordering::SeqCst);
+            let format = WireFormat::from_arg(args.next());
 
-            for i in 0..MASTER_SIZE {
-
-                let task = task::spawn(async move   {
-                    let result = MasterNode::start_node().await;
+            for _ in 0..MASTER_SIZE {
+                let tx = inbound_tx.clone();
+                let handle = task::spawn(async move {
+                    MasterNode::start_node(tx, format).await;
                 });
-
-                localtasks.push(task);
+                TASK_HANDLES.lock().unwrap().push(handle);
             }
-
-            // 等待所有线程执行完毕
-            for t in localtasks {
-                //handle.join().unwrap();
-                task::block_on(t);
-            }
-
-
         }
         Some("slave") => {
-            let mut localtasks = Vec::new();
+            SHUTDOWN.store(false, Ordering::SeqCst);
+            let format = WireFormat::from_arg(args.next());
 
-            for i in  0..SLAVE_SIZE {
-                let task = task::spawn(async move  {
-                    let result = SlaveNode::start_node().await;
+            for _ in 0..SLAVE_SIZE {
+                let tx = inbound_tx.clone();
+                let handle = task::spawn(async move {
+                    SlaveNode::start_node(tx, format).await;
                 });
-
-                localtasks.push(task);
-
-            }
-            // 等待所有线程执行完毕
-            for t in localtasks {
-                //handle.join().unwrap();
-                task::block_on(t);
+                TASK_HANDLES.lock().unwrap().push(handle);
             }
+        }
+        Some("stop") => {
+            println!("stopping master/slave task pools...");
+            SHUTDOWN.store(true, Ordering::SeqCst);
 
+            let handles: Vec<_> = TASK_HANDLES.lock().unwrap().drain(..).collect();
+            for handle in handles {
+                handle.await;
+            }
+            println!("master/slave task pools drained");
         }
 
         _ => {
-            println!("Invalid input: please add type master or slave");
+            println!("Invalid input: please add type master, slave or stop");
         }
     }
 }
@@ -0,0 +1,262 @@
+// Copyright 2023 Protocol Labs.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+//! ECDSA (NIST P-256) keys.
+
+use crate::error::{DecodingError, SigningError};
+use core::cmp;
+use core::fmt;
+use core::hash;
+use p256::ecdsa::signature::hazmat::{PrehashSigner, PrehashVerifier};
+use p256::ecdsa::{signature::Signer as _, signature::Verifier as _};
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use zeroize::Zeroize;
+
+/// An ECDSA (P-256) keypair.
+#[derive(Clone)]
+pub struct Keypair {
+    secret: SecretKey,
+    public: PublicKey,
+}
+
+impl Keypair {
+    /// Generate a new random P-256 keypair.
+    pub fn generate() -> Keypair {
+        SecretKey(p256::ecdsa::SigningKey::random(&mut rand::rngs::OsRng)).into()
+    }
+
+    /// The secret half of this keypair.
+    pub fn secret(&self) -> &SecretKey {
+        &self.secret
+    }
+
+    /// The public half of this keypair.
+    pub fn public(&self) -> &PublicKey {
+        &self.public
+    }
+
+    /// Best-effort zeroing of the in-memory secret key material.
+    pub fn non_secure_erase(&mut self) {
+        self.secret.non_secure_erase();
+    }
+}
+
+impl fmt::Debug for Keypair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Keypair").field("public", &self.public).finish()
+    }
+}
+
+impl From<SecretKey> for Keypair {
+    fn from(secret: SecretKey) -> Self {
+        let public = PublicKey(*secret.0.verifying_key());
+        Keypair { secret, public }
+    }
+}
+
+/// An ECDSA (P-256) secret key.
+#[derive(Clone)]
+pub struct SecretKey(p256::ecdsa::SigningKey);
+
+impl SecretKey {
+    /// Decode a secret key from a DER-encoded PKCS#8 `PrivateKeyInfo` or
+    /// SEC1 `ECPrivateKey` structure.
+    pub fn try_decode_der(der: &mut [u8]) -> Result<SecretKey, DecodingError> {
+        let key = p256::SecretKey::from_sec1_der(der)
+            .or_else(|_| {
+                use p256::pkcs8::DecodePrivateKey;
+                p256::SecretKey::from_pkcs8_der(der)
+            })
+            .map_err(|e| DecodingError::failed_to_parse("P-256 EC private key", e))?;
+        der.zeroize();
+        Ok(SecretKey(p256::ecdsa::SigningKey::from(key)))
+    }
+
+    /// Parse a secret key from its 32-byte scalar encoding.
+    pub fn try_from_bytes(bytes: &mut [u8]) -> Result<SecretKey, DecodingError> {
+        let key = p256::ecdsa::SigningKey::from_slice(bytes)
+            .map_err(|e| DecodingError::failed_to_parse("P-256 secret key", e))?;
+        bytes.zeroize();
+        Ok(SecretKey(key))
+    }
+
+    /// Sign a message, producing a DER-encoded ECDSA signature.
+    pub fn sign(&self, msg: &[u8]) -> Vec<u8> {
+        let sig: p256::ecdsa::Signature = self.0.sign(msg);
+        sig.to_der().as_bytes().to_vec()
+    }
+
+    /// Sign an already-computed digest directly (it is *not* hashed again),
+    /// producing a DER-encoded ECDSA signature.
+    pub fn sign_prehash(&self, digest: &[u8]) -> Result<Vec<u8>, SigningError> {
+        let sig: p256::ecdsa::Signature = self
+            .0
+            .sign_prehash(digest)
+            .map_err(|_| SigningError::new("ECDSA digest signing failed"))?;
+        Ok(sig.to_der().as_bytes().to_vec())
+    }
+
+    /// Encode this secret key as a DER-encoded `ECPrivateKey` structure
+    /// ([RFC5915]), as expected by [`crate::Keypair::to_pkcs8_der`]'s
+    /// `PrivateKeyInfo.privateKey` field.
+    ///
+    /// [RFC5915]: https://tools.ietf.org/html/rfc5915
+    pub fn encode_der(&self) -> Vec<u8> {
+        p256::SecretKey::from_bytes(&self.0.to_bytes())
+            .expect("valid P-256 scalar")
+            .to_sec1_der()
+            .expect("P-256 secret key to encode")
+            .as_bytes()
+            .to_vec()
+    }
+
+    /// Encode this secret key as its 32-byte scalar.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes().into()
+    }
+
+    /// Best-effort erasure of the in-memory secret key material: overwrites
+    /// the scalar with a well-known, non-secret placeholder.
+    pub fn non_secure_erase(&mut self) {
+        let mut placeholder = [0u8; 32];
+        placeholder[31] = 1;
+        if let Ok(key) = p256::ecdsa::SigningKey::from_bytes((&placeholder).into()) {
+            self.0 = key;
+        }
+    }
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.non_secure_erase();
+    }
+}
+
+/// An ECDSA (P-256) public key.
+#[derive(Clone, Copy)]
+pub struct PublicKey(p256::ecdsa::VerifyingKey);
+
+impl PublicKey {
+    /// Verify a DER-encoded ECDSA signature for a message using this public key.
+    #[must_use]
+    pub fn verify(&self, msg: &[u8], sig: &[u8]) -> bool {
+        let Ok(sig) = p256::ecdsa::Signature::from_der(sig) else {
+            return false;
+        };
+        self.0.verify(msg, &sig).is_ok()
+    }
+
+    /// Verify a DER-encoded ECDSA signature over an already-computed digest
+    /// (it is *not* hashed again).
+    #[must_use]
+    pub fn verify_prehash(&self, digest: &[u8], sig: &[u8]) -> bool {
+        let Ok(sig) = p256::ecdsa::Signature::from_der(sig) else {
+            return false;
+        };
+        self.0.verify_prehash(digest, &sig).is_ok()
+    }
+
+    /// Encode this public key into a `SubjectPublicKeyInfo` DER structure.
+    pub fn encode_der(&self) -> Vec<u8> {
+        use p256::pkcs8::EncodePublicKey;
+        self.0
+            .to_public_key_der()
+            .expect("P-256 public key to encode")
+            .as_bytes()
+            .to_vec()
+    }
+
+    /// Parse a public key from a `SubjectPublicKeyInfo` DER structure.
+    pub fn try_decode_der(spki_der: &[u8]) -> Result<PublicKey, DecodingError> {
+        use p256::pkcs8::DecodePublicKey;
+        p256::ecdsa::VerifyingKey::from_public_key_der(spki_der)
+            .map(PublicKey)
+            .map_err(|e| DecodingError::failed_to_parse("P-256 SPKI DER", e))
+    }
+
+    /// Encode this public key in SEC1 compressed form.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_encoded_point(true).as_bytes().to_vec()
+    }
+
+    /// Try to parse a public key from its SEC1 (compressed or uncompressed)
+    /// encoding.
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<PublicKey, DecodingError> {
+        p256::ecdsa::VerifyingKey::from_sec1_bytes(bytes)
+            .map(PublicKey)
+            .map_err(|e| DecodingError::failed_to_parse("P-256 public key", e))
+    }
+
+    /// The big-endian affine `(x, y)` coordinates of this public key.
+    pub fn to_affine_coordinates(&self) -> ([u8; 32], [u8; 32]) {
+        let point = self.0.to_encoded_point(false);
+        let mut x = [0u8; 32];
+        let mut y = [0u8; 32];
+        x.copy_from_slice(point.x().expect("uncompressed point has x"));
+        y.copy_from_slice(point.y().expect("uncompressed point has y"));
+        (x, y)
+    }
+
+    /// Build a public key from its big-endian affine `(x, y)` coordinates.
+    pub fn try_from_affine_coordinates(x: &[u8], y: &[u8]) -> Result<PublicKey, DecodingError> {
+        let point = p256::EncodedPoint::from_affine_coordinates(
+            x.try_into()
+                .map_err(|_| DecodingError::failed_to_parse("P-256 public key x", "expected 32 bytes"))?,
+            y.try_into()
+                .map_err(|_| DecodingError::failed_to_parse("P-256 public key y", "expected 32 bytes"))?,
+            false,
+        );
+        p256::ecdsa::VerifyingKey::from_encoded_point(&point)
+            .map(PublicKey)
+            .map_err(|e| DecodingError::failed_to_parse("P-256 public key", e))
+    }
+}
+
+impl fmt::Debug for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PublicKey").field(&bs58::encode(self.to_bytes()).into_string()).finish()
+    }
+}
+
+impl cmp::PartialEq for PublicKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bytes() == other.to_bytes()
+    }
+}
+
+impl cmp::Eq for PublicKey {}
+
+impl cmp::PartialOrd for PublicKey {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl cmp::Ord for PublicKey {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.to_bytes().cmp(&other.to_bytes())
+    }
+}
+
+impl hash::Hash for PublicKey {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.to_bytes().hash(state)
+    }
+}
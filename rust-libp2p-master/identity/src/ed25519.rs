@@ -0,0 +1,175 @@
+// Copyright 2023 Protocol Labs.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+//! Ed25519 keys.
+
+use crate::error::DecodingError;
+use core::cmp;
+use core::fmt;
+use core::hash;
+use ed25519_dalek::{self as dalek, Signer as _, Verifier as _};
+use zeroize::Zeroize;
+
+/// An Ed25519 keypair.
+#[derive(Clone)]
+pub struct Keypair(dalek::SigningKey);
+
+impl Keypair {
+    /// Generate a new random Ed25519 keypair.
+    pub fn generate() -> Keypair {
+        Keypair(dalek::SigningKey::generate(&mut rand::rngs::OsRng))
+    }
+
+    /// Sign a message using this keypair, producing a 64-byte signature.
+    pub fn sign(&self, msg: &[u8]) -> Vec<u8> {
+        self.0.sign(msg).to_bytes().to_vec()
+    }
+
+    /// Get the public key of this keypair.
+    pub fn public(&self) -> PublicKey {
+        PublicKey(self.0.verifying_key())
+    }
+
+    /// Return the 32-byte secret seed of this keypair.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+
+    /// Try to parse a keypair from its 32-byte secret seed, or from the
+    /// 64-byte `secret || public` form used in libp2p protobuf encodings.
+    pub fn try_from_bytes(bytes: &mut [u8]) -> Result<Keypair, DecodingError> {
+        if bytes.len() == 64 {
+            let keypair_bytes: [u8; 64] = (&*bytes).try_into().expect("length checked");
+            let key = dalek::SigningKey::from_keypair_bytes(&keypair_bytes)
+                .map_err(|e| DecodingError::failed_to_parse("Ed25519 keypair", e))?;
+            bytes.zeroize();
+            return Ok(Keypair(key));
+        }
+        let keypair = Keypair::from(SecretKey::try_from_bytes(bytes)?);
+        Ok(keypair)
+    }
+
+    /// Best-effort zeroing of the in-memory secret key material.
+    pub fn non_secure_erase(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl fmt::Debug for Keypair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Keypair").field("public", &self.public()).finish()
+    }
+}
+
+impl From<SecretKey> for Keypair {
+    fn from(secret: SecretKey) -> Self {
+        Keypair(dalek::SigningKey::from_bytes(&secret.0))
+    }
+}
+
+/// An Ed25519 secret key, i.e. a 32-byte seed.
+pub struct SecretKey([u8; 32]);
+
+impl SecretKey {
+    /// Try to parse a secret key from a 32-byte seed, zeroing the input on
+    /// success so the caller does not need to separately erase it.
+    pub fn try_from_bytes(bytes: impl AsMut<[u8]>) -> Result<SecretKey, DecodingError> {
+        let mut bytes = bytes;
+        let bytes = bytes.as_mut();
+        if bytes.len() != 32 {
+            return Err(DecodingError::failed_to_parse(
+                "Ed25519 secret key",
+                "expected 32 bytes",
+            ));
+        }
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(bytes);
+        bytes.zeroize();
+        Ok(SecretKey(seed))
+    }
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// An Ed25519 public key.
+#[derive(Clone)]
+pub struct PublicKey(dalek::VerifyingKey);
+
+impl PublicKey {
+    /// Verify a signature for a message using this public key.
+    #[must_use]
+    pub fn verify(&self, msg: &[u8], sig: &[u8]) -> bool {
+        let Ok(sig) = dalek::Signature::from_slice(sig) else {
+            return false;
+        };
+        self.0.verify(msg, &sig).is_ok()
+    }
+
+    /// Encode this public key as its 32-byte compressed form.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+
+    /// Try to parse a public key from its 32-byte compressed form.
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<PublicKey, DecodingError> {
+        let bytes: [u8; 32] = bytes.try_into().map_err(|_| {
+            DecodingError::failed_to_parse("Ed25519 public key", "expected 32 bytes")
+        })?;
+        dalek::VerifyingKey::from_bytes(&bytes)
+            .map(PublicKey)
+            .map_err(|e| DecodingError::failed_to_parse("Ed25519 public key", e))
+    }
+}
+
+impl fmt::Debug for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PublicKey").field(&bs58::encode(self.to_bytes()).into_string()).finish()
+    }
+}
+
+impl cmp::PartialEq for PublicKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bytes() == other.to_bytes()
+    }
+}
+
+impl cmp::Eq for PublicKey {}
+
+impl cmp::PartialOrd for PublicKey {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl cmp::Ord for PublicKey {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.to_bytes().cmp(&other.to_bytes())
+    }
+}
+
+impl hash::Hash for PublicKey {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.to_bytes().hash(state)
+    }
+}
@@ -0,0 +1,248 @@
+// Copyright 2023 Protocol Labs.
+//
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the "Software"),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+//! RSA keys.
+
+use crate::error::{DecodingError, SigningError};
+use crate::RsaSigningOptions;
+use core::cmp;
+use core::fmt;
+use core::hash;
+use rsa::pkcs1::{DecodeRsaPrivateKey, EncodeRsaPrivateKey};
+use rsa::pkcs8::{DecodePrivateKey, EncodePrivateKey, DecodePublicKey, EncodePublicKey};
+use rsa::traits::PublicKeyParts;
+use rsa::{BigUint, Pkcs1v15Sign, Pss};
+use sha2::{Digest, Sha256, Sha512};
+use zeroize::Zeroize;
+
+/// An RSA keypair.
+#[derive(Clone)]
+pub struct Keypair(rsa::RsaPrivateKey);
+
+impl Keypair {
+    /// Decode a keypair from a DER-encoded PKCS#8 `PrivateKeyInfo` structure.
+    pub fn try_decode_pkcs8(der: &mut [u8]) -> Result<Keypair, DecodingError> {
+        let key = rsa::RsaPrivateKey::from_pkcs8_der(der)
+            .map_err(|e| DecodingError::failed_to_parse("RSA PKCS#8 DER", e))?;
+        der.zeroize();
+        Ok(Keypair(key))
+    }
+
+    /// Decode a keypair from a DER-encoded PKCS#1 `RSAPrivateKey` structure,
+    /// the format used by libp2p's own protobuf envelope.
+    pub fn try_decode_pkcs1(der: &mut [u8]) -> Result<Keypair, DecodingError> {
+        let key = rsa::RsaPrivateKey::from_pkcs1_der(der)
+            .map_err(|e| DecodingError::failed_to_parse("RSA PKCS#1 DER", e))?;
+        der.zeroize();
+        Ok(Keypair(key))
+    }
+
+    /// Build a keypair from its public modulus `n`, public exponent `e`,
+    /// and private exponent `d` (all big-endian), as found in an RSA JWK.
+    pub fn try_from_modulus_exponent_d(
+        n: &[u8],
+        e: &[u8],
+        d: &[u8],
+    ) -> Result<Keypair, DecodingError> {
+        let n = BigUint::from_bytes_be(n);
+        let e = BigUint::from_bytes_be(e);
+        let d = BigUint::from_bytes_be(d);
+        let key = rsa::RsaPrivateKey::from_components(n, e, d, Vec::new())
+            .map_err(|err| DecodingError::failed_to_parse("RSA modulus/exponent", err))?;
+        Ok(Keypair(key))
+    }
+
+    /// Encode this keypair as a DER-encoded PKCS#8 `PrivateKeyInfo`
+    /// structure.
+    pub fn to_pkcs8_der(&self) -> Result<Vec<u8>, DecodingError> {
+        self.0
+            .to_pkcs8_der()
+            .map(|der| der.as_bytes().to_vec())
+            .map_err(|e| DecodingError::failed_to_parse("RSA PKCS#8 DER", e))
+    }
+
+    /// Encode this keypair as a DER-encoded PKCS#1 `RSAPrivateKey`
+    /// structure, the format used by libp2p's own protobuf envelope.
+    pub fn to_pkcs1_der(&self) -> Result<Vec<u8>, DecodingError> {
+        self.0
+            .to_pkcs1_der()
+            .map(|der| der.as_bytes().to_vec())
+            .map_err(|e| DecodingError::failed_to_parse("RSA PKCS#1 DER", e))
+    }
+
+    /// Sign a message using RSASSA-PKCS1-v1_5/SHA-256.
+    pub fn sign(&self, msg: &[u8]) -> Result<Vec<u8>, SigningError> {
+        self.sign_with_options(msg, RsaSigningOptions::Pkcs1v15Sha256)
+    }
+
+    /// Sign a message using the given RSA signature scheme and digest
+    /// algorithm.
+    pub fn sign_with_options(
+        &self,
+        msg: &[u8],
+        options: RsaSigningOptions,
+    ) -> Result<Vec<u8>, SigningError> {
+        let sign = |scheme, hashed: &[u8]| {
+            self.0
+                .sign(scheme, hashed)
+                .map_err(|_| SigningError::new("RSA signing failed"))
+        };
+
+        match options {
+            RsaSigningOptions::Pkcs1v15Sha256 => {
+                let hashed = Sha256::digest(msg);
+                sign(Pkcs1v15Sign::new::<Sha256>(), &hashed)
+            }
+            RsaSigningOptions::Pkcs1v15Sha512 => {
+                let hashed = Sha512::digest(msg);
+                sign(Pkcs1v15Sign::new::<Sha512>(), &hashed)
+            }
+            RsaSigningOptions::PssSha256 => {
+                let hashed = Sha256::digest(msg);
+                self.0
+                    .sign_with_rng(&mut rand::rngs::OsRng, Pss::new::<Sha256>(), &hashed)
+                    .map_err(|_| SigningError::new("RSA signing failed"))
+            }
+            RsaSigningOptions::PssSha512 => {
+                let hashed = Sha512::digest(msg);
+                self.0
+                    .sign_with_rng(&mut rand::rngs::OsRng, Pss::new::<Sha512>(), &hashed)
+                    .map_err(|_| SigningError::new("RSA signing failed"))
+            }
+        }
+    }
+
+    /// Sign an already-computed SHA-256 digest directly using
+    /// RSASSA-PKCS1-v1_5.
+    pub fn sign_prehash(&self, digest: &[u8]) -> Result<Vec<u8>, SigningError> {
+        self.0
+            .sign(Pkcs1v15Sign::new::<Sha256>(), digest)
+            .map_err(|_| SigningError::new("RSA digest signing failed"))
+    }
+
+    /// Get the public key of this keypair.
+    pub fn public(&self) -> PublicKey {
+        PublicKey(self.0.to_public_key())
+    }
+
+    /// The private exponent `d` of this key, big-endian.
+    pub fn private_exponent(&self) -> Vec<u8> {
+        self.0.d().to_bytes_be()
+    }
+
+    /// Best-effort zeroing of the in-memory secret key material.
+    pub fn non_secure_erase(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl fmt::Debug for Keypair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Keypair").field("public", &self.public()).finish()
+    }
+}
+
+/// An RSA public key.
+#[derive(Clone)]
+pub struct PublicKey(rsa::RsaPublicKey);
+
+impl PublicKey {
+    /// Parse a public key from a `SubjectPublicKeyInfo` DER structure
+    /// (X.509 `RSAPublicKey`/`rsaEncryption`).
+    pub fn try_decode_x509(spki_der: &[u8]) -> Result<PublicKey, DecodingError> {
+        rsa::RsaPublicKey::from_public_key_der(spki_der)
+            .map(PublicKey)
+            .map_err(|e| DecodingError::failed_to_parse("RSA SPKI DER", e))
+    }
+
+    /// Encode this public key into a `SubjectPublicKeyInfo` DER structure.
+    pub fn encode_x509(&self) -> Vec<u8> {
+        self.0
+            .to_public_key_der()
+            .expect("RSA public key to encode")
+            .as_bytes()
+            .to_vec()
+    }
+
+    /// Verify an RSASSA-PKCS1-v1_5/SHA-256 signature for a message using
+    /// this public key.
+    #[must_use]
+    pub fn verify(&self, msg: &[u8], sig: &[u8]) -> bool {
+        let hashed = Sha256::digest(msg);
+        self.0
+            .verify(Pkcs1v15Sign::new::<Sha256>(), &hashed, sig)
+            .is_ok()
+    }
+
+    /// Verify an RSASSA-PKCS1-v1_5/SHA-256 signature over an already-computed
+    /// digest.
+    #[must_use]
+    pub fn verify_prehash(&self, digest: &[u8], sig: &[u8]) -> bool {
+        self.0
+            .verify(Pkcs1v15Sign::new::<Sha256>(), digest, sig)
+            .is_ok()
+    }
+
+    /// The public modulus `n` and exponent `e`, big-endian.
+    pub fn to_modulus_exponent(&self) -> (Vec<u8>, Vec<u8>) {
+        (self.0.n().to_bytes_be(), self.0.e().to_bytes_be())
+    }
+
+    /// Build a public key from its modulus `n` and exponent `e`, big-endian.
+    pub fn try_from_modulus_exponent(n: &[u8], e: &[u8]) -> Result<PublicKey, DecodingError> {
+        let n = BigUint::from_bytes_be(n);
+        let e = BigUint::from_bytes_be(e);
+        rsa::RsaPublicKey::new(n, e)
+            .map(PublicKey)
+            .map_err(|err| DecodingError::failed_to_parse("RSA modulus/exponent", err))
+    }
+}
+
+impl fmt::Debug for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PublicKey").field(&bs58::encode(self.encode_x509()).into_string()).finish()
+    }
+}
+
+impl cmp::PartialEq for PublicKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl cmp::Eq for PublicKey {}
+
+impl cmp::PartialOrd for PublicKey {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl cmp::Ord for PublicKey {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.to_modulus_exponent().cmp(&other.to_modulus_exponent())
+    }
+}
+
+impl hash::Hash for PublicKey {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.to_modulus_exponent().hash(state)
+    }
+}